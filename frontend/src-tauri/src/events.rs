@@ -0,0 +1,66 @@
+use serde::Serialize;
+use tauri::Window;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressPayload {
+    pub operation_id: String,
+    pub processed: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DonePayload {
+    pub operation_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorPayload {
+    pub operation_id: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoPayload {
+    pub operation_id: String,
+    pub message: String,
+}
+
+pub fn emit_progress(window: &Window, operation_id: &str, processed: usize, total: usize) {
+    let _ = window.emit(
+        "operation://progress",
+        ProgressPayload {
+            operation_id: operation_id.to_string(),
+            processed,
+            total,
+        },
+    );
+}
+
+pub fn emit_done(window: &Window, operation_id: &str) {
+    let _ = window.emit(
+        "operation://done",
+        DonePayload {
+            operation_id: operation_id.to_string(),
+        },
+    );
+}
+
+pub fn emit_error(window: &Window, operation_id: &str, message: impl Into<String>) {
+    let _ = window.emit(
+        "operation://error",
+        ErrorPayload {
+            operation_id: operation_id.to_string(),
+            message: message.into(),
+        },
+    );
+}
+
+pub fn emit_info(window: &Window, operation_id: &str, message: impl Into<String>) {
+    let _ = window.emit(
+        "operation://info",
+        InfoPayload {
+            operation_id: operation_id.to_string(),
+            message: message.into(),
+        },
+    );
+}
@@ -0,0 +1,192 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+use tauri::Window;
+use uuid::Uuid;
+
+use crate::events::{emit_done, emit_error, emit_info, emit_progress};
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum FsError {
+    NotFound,
+    PermissionDenied,
+    IsDirectory,
+    Io(String),
+}
+
+impl From<io::Error> for FsError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => FsError::NotFound,
+            io::ErrorKind::PermissionDenied => FsError::PermissionDenied,
+            _ => FsError::Io(err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct EntryMetadata {
+    name: String,
+    path: String,
+    size: u64,
+    is_directory: bool,
+    is_file: bool,
+    is_symlink: bool,
+    permission: String,
+    directory_item_count: Option<usize>,
+    created: Option<u64>,
+    modified: Option<u64>,
+    accessed: Option<u64>,
+}
+
+fn system_time_to_secs(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+#[cfg(unix)]
+fn format_permission(mode: u32) -> String {
+    let rwx = |bits: u32| -> String {
+        let r = if bits & 0b100 != 0 { "r" } else { "-" };
+        let w = if bits & 0b010 != 0 { "w" } else { "-" };
+        let x = if bits & 0b001 != 0 { "x" } else { "-" };
+        format!("{}{}{}", r, w, x)
+    };
+    let owner = rwx((mode >> 6) & 0o7);
+    let group = rwx((mode >> 3) & 0o7);
+    let other = rwx(mode & 0o7);
+    format!("{:04o} ({}{}{})", mode & 0o7777, owner, group, other)
+}
+
+#[cfg(unix)]
+fn entry_permission(metadata: &fs::Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+    format_permission(metadata.permissions().mode())
+}
+
+#[cfg(not(unix))]
+fn entry_permission(metadata: &fs::Metadata) -> String {
+    if metadata.permissions().readonly() {
+        "r--r--r--".to_string()
+    } else {
+        "rw-rw-rw-".to_string()
+    }
+}
+
+fn directory_item_count(path: &Path) -> Option<usize> {
+    fs::read_dir(path).ok().map(|entries| entries.count())
+}
+
+#[tauri::command]
+pub async fn list_directory(path: String) -> Result<Vec<EntryMetadata>, String> {
+    let dir = Path::new(&path);
+    let entries = fs::read_dir(dir).map_err(|e| e.to_string())?;
+
+    let mut result = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let entry_path = entry.path();
+        let metadata = entry.metadata().map_err(|e| e.to_string())?;
+        let file_type = metadata.file_type();
+
+        let is_directory = file_type.is_dir();
+
+        result.push(EntryMetadata {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            path: entry_path.to_string_lossy().into_owned(),
+            size: metadata.len(),
+            is_directory,
+            is_file: file_type.is_file(),
+            is_symlink: file_type.is_symlink(),
+            permission: entry_permission(&metadata),
+            directory_item_count: if is_directory {
+                directory_item_count(&entry_path)
+            } else {
+                None
+            },
+            created: system_time_to_secs(metadata.created()),
+            modified: system_time_to_secs(metadata.modified()),
+            accessed: system_time_to_secs(metadata.accessed()),
+        });
+    }
+
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn delete_entry(path: String, permanent: bool, recursive: bool) -> Result<(), FsError> {
+    let entry_path = Path::new(&path);
+    let metadata = fs::metadata(entry_path)?;
+
+    if !permanent {
+        return trash::delete(entry_path).map_err(|e| FsError::Io(e.to_string()));
+    }
+
+    if metadata.is_dir() {
+        if recursive {
+            fs::remove_dir_all(entry_path)?;
+        } else {
+            return Err(FsError::IsDirectory);
+        }
+    } else {
+        fs::remove_file(entry_path)?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn delete_entries_bulk(
+    paths: Vec<String>,
+    permanent: bool,
+    recursive: bool,
+    window: Window,
+) -> Result<String, FsError> {
+    let operation_id = Uuid::new_v4().to_string();
+    let op_id = operation_id.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let total = paths.len();
+        emit_info(
+            &window,
+            &op_id,
+            format!("starting delete of {} entries", total),
+        );
+
+        for (processed, path) in paths.into_iter().enumerate() {
+            let entry_path = Path::new(&path);
+            let result = if permanent {
+                match fs::metadata(entry_path) {
+                    Ok(metadata) if metadata.is_dir() => {
+                        if recursive {
+                            fs::remove_dir_all(entry_path).map_err(FsError::from)
+                        } else {
+                            Err(FsError::IsDirectory)
+                        }
+                    }
+                    Ok(_) => fs::remove_file(entry_path).map_err(FsError::from),
+                    Err(e) => Err(FsError::from(e)),
+                }
+            } else {
+                trash::delete(entry_path).map_err(|e| FsError::Io(e.to_string()))
+            };
+
+            if let Err(e) = result {
+                emit_error(&window, &op_id, format!("{:?}", e));
+                return;
+            }
+
+            emit_progress(&window, &op_id, processed + 1, total);
+        }
+
+        emit_done(&window, &op_id);
+    });
+
+    Ok(operation_id)
+}
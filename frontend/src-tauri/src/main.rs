@@ -3,22 +3,47 @@
     windows_subsystem = "windows"
 )]
 
+mod events;
+mod fs_ops;
+mod panic_hook;
+mod settings;
+mod thumbnails;
+
 use tauri::Manager;
-use std::fs;
 
-#[tauri::command]
-async fn delete_file(path: String) -> Result<(), String> {
-    fs::remove_file(path).map_err(|e| e.to_string())
-}
+use fs_ops::{delete_entries_bulk, delete_entry, list_directory};
+use settings::{get_settings, update_settings, Context};
+use thumbnails::{get_thumbnail, read_file};
 
 fn main() {
+    let context = tauri::generate_context!();
+    let config_dir = tauri::api::path::app_config_dir(context.config())
+        .expect("could not resolve app config dir");
+    let cache_dir = tauri::api::path::app_cache_dir(context.config())
+        .expect("could not resolve app cache dir");
+    let log_dir = tauri::api::path::app_log_dir(context.config())
+        .expect("could not resolve app log dir");
+
+    panic_hook::install(log_dir);
+
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![delete_file])
+        .manage(Context::new(config_dir, cache_dir))
+        .invoke_handler(tauri::generate_handler![
+            delete_entry,
+            delete_entries_bulk,
+            list_directory,
+            get_settings,
+            update_settings,
+            read_file,
+            get_thumbnail
+        ])
         .setup(|app| {
-            let window = app.get_window("main").unwrap();
-            window.show().unwrap();
+            let window = app
+                .get_window("main")
+                .ok_or("main window was not found during setup")?;
+            window.show()?;
             Ok(())
         })
-        .run(tauri::generate_context!())
+        .run(context)
         .expect("error while running tauri application");
 }
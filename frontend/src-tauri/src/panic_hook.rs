@@ -0,0 +1,40 @@
+use std::backtrace::Backtrace;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tauri::api::dialog::blocking::message;
+use tauri::Window;
+
+pub fn install(log_dir: PathBuf) {
+    std::panic::set_hook(Box::new(move |info| {
+        let backtrace = Backtrace::force_capture();
+        let crash_message = format!("panic occurred: {}\n{}", info, backtrace);
+
+        log::error!("{}", crash_message);
+
+        if let Err(e) = write_crash_log(&log_dir, &crash_message) {
+            log::error!("failed to write crash log: {}", e);
+        }
+
+        message(
+            None::<&Window>,
+            "Application Crashed",
+            "Something went wrong and the application needs to close. A crash log has been saved.",
+        );
+
+        std::process::exit(1);
+    }));
+}
+
+fn write_crash_log(log_dir: &Path, message: &str) -> std::io::Result<()> {
+    fs::create_dir_all(log_dir)?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let path = log_dir.join(format!("crash-{}.log", timestamp));
+    fs::write(path, message)
+}
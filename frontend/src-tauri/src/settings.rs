@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settings {
+    pub last_visited_directory: Option<String>,
+    pub confirm_before_delete: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            last_visited_directory: None,
+            confirm_before_delete: true,
+        }
+    }
+}
+
+pub struct Context {
+    pub settings: Mutex<Settings>,
+    pub config_dir: PathBuf,
+    pub cache_dir: PathBuf,
+}
+
+impl Context {
+    pub fn new(config_dir: PathBuf, cache_dir: PathBuf) -> Self {
+        Context {
+            settings: Mutex::new(load_settings(&config_dir)),
+            config_dir,
+            cache_dir,
+        }
+    }
+}
+
+fn settings_path(config_dir: &Path) -> PathBuf {
+    config_dir.join(SETTINGS_FILE)
+}
+
+pub fn load_settings(config_dir: &Path) -> Settings {
+    fs::read_to_string(settings_path(config_dir))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_settings(config_dir: &Path, settings: &Settings) -> Result<(), String> {
+    fs::create_dir_all(config_dir).map_err(|e| e.to_string())?;
+    let contents = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    fs::write(settings_path(config_dir), contents).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_settings(context: State<'_, Context>) -> Result<Settings, String> {
+    Ok(context.settings.lock().unwrap().clone())
+}
+
+#[tauri::command]
+pub async fn update_settings(
+    settings: Settings,
+    context: State<'_, Context>,
+) -> Result<(), String> {
+    save_settings(&context.config_dir, &settings)?;
+    *context.settings.lock().unwrap() = settings;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Cursor;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use base64::Engine;
+use image::imageops::FilterType;
+use tauri::State;
+
+use crate::settings::Context;
+
+fn cache_key(path: &str, modified: u64, max_size: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    modified.hash(&mut hasher);
+    max_size.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[tauri::command]
+pub async fn read_file(path: String) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_thumbnail(
+    path: String,
+    max_size: u32,
+    context: State<'_, Context>,
+) -> Result<String, String> {
+    let source = Path::new(&path);
+    let modified = fs::metadata(source)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| e.to_string())?
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+
+    let cache_dir = context.cache_dir.join("thumbnails");
+    fs::create_dir_all(&cache_dir).map_err(|e| e.to_string())?;
+    let cache_path = cache_dir.join(format!("{}.png", cache_key(&path, modified, max_size)));
+
+    let bytes = if cache_path.exists() {
+        fs::read(&cache_path).map_err(|e| e.to_string())?
+    } else {
+        let image = image::open(source).map_err(|e| e.to_string())?;
+        let thumbnail = image.resize(max_size, max_size, FilterType::Lanczos3);
+
+        let mut bytes = Vec::new();
+        thumbnail
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .map_err(|e| e.to_string())?;
+
+        fs::write(&cache_path, &bytes).map_err(|e| e.to_string())?;
+        bytes
+    };
+
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    ))
+}